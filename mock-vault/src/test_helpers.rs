@@ -1,6 +1,8 @@
 use std::str::FromStr;
 
+use cosmos_vault_standard::asset::AssetInfo;
 use cosmwasm_std::{coin, Coin, Decimal, Uint128};
+use cw20::{BalanceResponse, Cw20QueryMsg};
 use cw_it::cw_multi_test::{ContractWrapper, StargateKeeper, StargateMessageHandler};
 use cw_it::multi_test::modules::TokenFactory;
 use cw_it::multi_test::MultiTestRunner;
@@ -143,6 +145,53 @@ where
         )
     }
 
+    /// Uploads and instantiates the vault contract with the lockup extension
+    /// enabled, using `duration` (in seconds) as the lockup duration, and
+    /// returns a new instance of the robot.
+    #[cfg(feature = "lockup")]
+    fn instantiate_with_lockup(
+        runner: &'a R,
+        admin: &'a SigningAccount,
+        base_token: &str,
+        duration: u64,
+        denom_creation_fee: Option<Coin>,
+    ) -> DefaultVaultRobot<'a, R>
+    where
+        Self: Sized,
+    {
+        let wasm = Wasm::new(runner);
+
+        let mock_vault = get_mock_vault_contract();
+        let code_id = runner.store_code(mock_vault, admin).unwrap();
+
+        let msg = crate::msg::InstantiateMsg {
+            base_token: base_token.to_string(),
+            lockup_duration: duration,
+        };
+        let vault_addr = wasm
+            .instantiate(
+                code_id,
+                &msg,
+                Some(&admin.address()),
+                Some("mock_vault"),
+                &denom_creation_fee.map_or_else(|| vec![], |f| vec![f]),
+                admin,
+            )
+            .unwrap()
+            .data
+            .address;
+
+        let vault_token = format!("factory/{}/{}", vault_addr, MOCK_VAULT_TOKEN_SUBDENOM);
+
+        Self::default_vault_robot(
+            runner,
+            admin,
+            base_token.to_string(),
+            vault_token,
+            vault_addr,
+        )
+    }
+
     /// Deposit base tokens into the vault and return a reference to the robot.
     fn deposit_to_vault(&self, amount: impl Into<Uint128>, signer: &SigningAccount) -> &Self {
         let amount: Uint128 = amount.into();
@@ -203,6 +252,216 @@ where
     fn query_vault_token_balance(&self, account: impl Into<String>) -> Uint128 {
         self.query_native_token_balance(account, self.vault_token())
     }
+
+    /// The `AssetInfo` of the base token. Defaults to `AssetInfo::Native`,
+    /// since `DefaultVaultRobot` only supports native base tokens; robots for
+    /// cw20-based vaults should override this.
+    fn base_token_asset_info(&self) -> AssetInfo {
+        AssetInfo::Native(self.base_token().to_string())
+    }
+
+    /// Query the balance of `account` for the given `asset`, dispatching on
+    /// whether it is a native or cw20 asset.
+    fn query_asset_balance(&self, asset: &AssetInfo, account: impl Into<String>) -> Uint128 {
+        let account = account.into();
+
+        match asset {
+            AssetInfo::Native(denom) => self.query_native_token_balance(account, denom),
+            AssetInfo::Cw20(contract_addr) => {
+                let res: BalanceResponse = self
+                    .wasm()
+                    .query(
+                        contract_addr.as_str(),
+                        &Cw20QueryMsg::Balance { address: account },
+                    )
+                    .unwrap();
+
+                res.balance
+            }
+        }
+    }
+
+    /// Query the base token balance of `account`, handling both native and
+    /// cw20 base tokens.
+    fn query_base_token_balance(&self, account: impl Into<String>) -> Uint128 {
+        self.query_asset_balance(&self.base_token_asset_info(), account)
+    }
+
+    /// Query the current share price, i.e. `total_base_tokens /
+    /// total_vault_tokens`.
+    fn query_share_price(&self) -> Decimal {
+        self.wasm()
+            .query(self.vault_addr(), &crate::msg::QueryMsg::SharePrice {})
+            .unwrap()
+    }
+
+    /// Assert that the share price is equal to `expected`, within
+    /// `max_rel_diff` relative difference, and return a reference to the
+    /// robot.
+    fn assert_share_price(&self, expected: Decimal, max_rel_diff: &str) -> &Self {
+        assert_almost_eq(self.query_share_price(), expected, max_rel_diff);
+
+        self
+    }
+
+    /// Permissionlessly collect pending external incentives into the vault
+    /// and return a reference to the robot.
+    #[cfg(feature = "rewards")]
+    fn collect_rewards(&self, signer: &SigningAccount) -> &Self {
+        let msg =
+            crate::msg::ExecuteMsg::VaultExtension(crate::msg::ExtensionExecuteMsg::Incentives(
+                crate::msg::IncentivesExecuteMsg::CollectRewards {},
+            ));
+        self.wasm()
+            .execute(self.vault_addr(), &msg, &[], signer)
+            .unwrap();
+
+        self
+    }
+
+    /// Query the rewards pending distribution. If `user` is `None`, returns
+    /// the rewards pending for the vault as a whole.
+    #[cfg(feature = "rewards")]
+    fn query_pending_rewards(&self, user: Option<String>) -> Vec<Coin> {
+        let msg = crate::msg::QueryMsg::VaultExtension(crate::msg::ExtensionQueryMsg::Incentives(
+            crate::msg::IncentivesQueryMsg::PendingRewards { user },
+        ));
+        self.wasm().query(self.vault_addr(), &msg).unwrap()
+    }
+
+    /// Advance the chain's block time by `seconds`. Works with both the
+    /// `MultiTestRunner` and `OsmosisTestApp` backends, so lockup-duration
+    /// tests are portable across runners.
+    #[cfg(feature = "lockup")]
+    fn increase_time(&self, seconds: u64) -> &Self {
+        self.runner().increase_time(seconds).unwrap();
+
+        self
+    }
+
+    /// Send vault tokens into the lockup extension, starting the unlocking
+    /// period for `amount`, and return a reference to the robot.
+    #[cfg(feature = "lockup")]
+    fn unlock(&self, amount: impl Into<Uint128>, signer: &SigningAccount) -> &Self {
+        let amount: Uint128 = amount.into();
+
+        let msg = crate::msg::ExecuteMsg::VaultExtension(crate::msg::ExtensionExecuteMsg::Lockup(
+            crate::msg::LockupExecuteMsg::Unlock { amount },
+        ));
+        self.wasm()
+            .execute(
+                self.vault_addr(),
+                &msg,
+                &[coin(amount.u128(), self.vault_token())],
+                signer,
+            )
+            .unwrap();
+
+        self
+    }
+
+    /// Withdraw the base tokens backing an unlocking position that has
+    /// completed its lockup duration, and return a reference to the robot.
+    #[cfg(feature = "lockup")]
+    fn withdraw_unlocked(&self, lockup_id: u64, signer: &SigningAccount) -> &Self {
+        let msg = crate::msg::ExecuteMsg::VaultExtension(crate::msg::ExtensionExecuteMsg::Lockup(
+            crate::msg::LockupExecuteMsg::WithdrawUnlocked { lockup_id },
+        ));
+        self.wasm()
+            .execute(self.vault_addr(), &msg, &[], signer)
+            .unwrap();
+
+        self
+    }
+
+    /// Force-unlock `lockup_id`, bypassing the remaining lockup duration.
+    /// Only callable by the address(es) authorized by the force-unlock
+    /// extension (e.g. a money-market liquidator), and return a reference to
+    /// the robot.
+    #[cfg(feature = "force-unlock")]
+    fn force_unlock(&self, lockup_id: u64, signer: &SigningAccount) -> &Self {
+        let msg =
+            crate::msg::ExecuteMsg::VaultExtension(crate::msg::ExtensionExecuteMsg::ForceUnlock(
+                crate::msg::ForceUnlockExecuteMsg::ForceUnlock { lockup_id },
+            ));
+        self.wasm()
+            .execute(self.vault_addr(), &msg, &[], signer)
+            .unwrap();
+
+        self
+    }
+
+    /// Force-redeem `amount` of vault tokens directly, bypassing the lockup
+    /// extension entirely instead of going through `Unlock` +
+    /// `WithdrawUnlocked`. Only callable by the address(es) authorized by the
+    /// force-unlock extension, and return a reference to the robot.
+    #[cfg(feature = "force-unlock")]
+    fn force_redeem(&self, amount: impl Into<Uint128>, signer: &SigningAccount) -> &Self {
+        let amount: Uint128 = amount.into();
+
+        let msg =
+            crate::msg::ExecuteMsg::VaultExtension(crate::msg::ExtensionExecuteMsg::ForceUnlock(
+                crate::msg::ForceUnlockExecuteMsg::ForceRedeem { amount },
+            ));
+        self.wasm()
+            .execute(
+                self.vault_addr(),
+                &msg,
+                &[coin(amount.u128(), self.vault_token())],
+                signer,
+            )
+            .unwrap();
+
+        self
+    }
+
+    /// Query all unlocking positions owned by `owner`.
+    #[cfg(feature = "lockup")]
+    fn query_unlocking_positions(
+        &self,
+        owner: impl Into<String>,
+    ) -> Vec<crate::msg::UnlockingPosition> {
+        let msg = crate::msg::QueryMsg::VaultExtension(crate::msg::ExtensionQueryMsg::Lockup(
+            crate::msg::LockupQueryMsg::UnlockingPositions {
+                owner: owner.into(),
+            },
+        ));
+        self.wasm().query(self.vault_addr(), &msg).unwrap()
+    }
+
+    /// Query a single unlocking position by id.
+    #[cfg(feature = "lockup")]
+    fn query_unlocking_position(&self, lockup_id: u64) -> crate::msg::UnlockingPosition {
+        let msg = crate::msg::QueryMsg::VaultExtension(crate::msg::ExtensionQueryMsg::Lockup(
+            crate::msg::LockupQueryMsg::UnlockingPosition { lockup_id },
+        ));
+        self.wasm().query(self.vault_addr(), &msg).unwrap()
+    }
+
+    /// Assert that the unlocking position with id `lockup_id` equals
+    /// `expected`, and return a reference to the robot.
+    #[cfg(feature = "lockup")]
+    fn assert_unlocking_position_eq(
+        &self,
+        lockup_id: u64,
+        expected: &crate::msg::UnlockingPosition,
+    ) -> &Self {
+        assert_eq!(&self.query_unlocking_position(lockup_id), expected);
+
+        self
+    }
+
+    /// Assert that `account`'s vault token balance equals `expected`, and
+    /// return a reference to the robot.
+    fn assert_vault_token_balance(
+        &self,
+        account: impl Into<String>,
+        expected: impl Into<Uint128>,
+    ) -> &Self {
+        assert_eq!(self.query_vault_token_balance(account), expected.into());
+
+        self
+    }
 }
 
 /// A simple testing robot for testing vault contracts.
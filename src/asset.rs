@@ -0,0 +1,47 @@
+use std::fmt;
+
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+
+/// Identifies either a native Cosmos SDK denom or a cw20 contract.
+///
+/// The standard already notes in [`crate::msg::VaultStandardExecuteMsg::Redeem`]
+/// that base and vault tokens may be either kind, but previously that was
+/// only conveyed by convention (a cw20 contract address looks like any other
+/// string). `AssetInfo` makes the distinction explicit so callers know which
+/// query/transfer path in [`crate::querier`] to dispatch on without having
+/// to guess from the string's shape.
+#[cw_serde]
+pub enum AssetInfo {
+    /// A native Cosmos SDK denom, e.g. `"uosmo"` or a token-factory denom.
+    Native(String),
+    /// The contract address of a cw20 token.
+    Cw20(Addr),
+}
+
+impl AssetInfo {
+    /// Returns the native denom, if this is `AssetInfo::Native`.
+    pub fn as_native(&self) -> Option<&str> {
+        match self {
+            AssetInfo::Native(denom) => Some(denom),
+            AssetInfo::Cw20(_) => None,
+        }
+    }
+
+    /// Returns the cw20 contract address, if this is `AssetInfo::Cw20`.
+    pub fn as_cw20(&self) -> Option<&Addr> {
+        match self {
+            AssetInfo::Native(_) => None,
+            AssetInfo::Cw20(contract_addr) => Some(contract_addr),
+        }
+    }
+}
+
+impl fmt::Display for AssetInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetInfo::Native(denom) => write!(f, "{}", denom),
+            AssetInfo::Cw20(contract_addr) => write!(f, "{}", contract_addr),
+        }
+    }
+}
@@ -0,0 +1,399 @@
+//! Bonding-curve pricing for vaults that want price-impact or continuous-
+//! token semantics instead of the default linear `assets / supply` ratio.
+//!
+//! A [`Curve`] relates the total vault token `supply` to the `reserve` of
+//! base tokens backing it. `ConvertToShares`/`PreviewDeposit` then compute
+//! `supply(reserve + deposit, Rounding::Down) - supply(reserve,
+//! Rounding::Up)`, and `ConvertToAssets`/`PreviewRedeem` compute
+//! `reserve(supply, Rounding::Down) - reserve(supply - burn, Rounding::Up)`.
+//! Rounding down on mint and up on the subtracted term of a burn means the
+//! vault never mints more shares, or pays out more reserve, than the curve
+//! allows.
+//!
+//! All intermediate arithmetic is carried out in [`Uint512`] and only
+//! narrowed back to [`Uint128`] once the final result is known to fit.
+//! `Uint256` is not wide enough for [`SquareRoot::supply`]: it squares
+//! `3 * reserve * one_atomics / (2 * slope_atomics)`, which for a `Uint128`
+//! reserve and a small slope can itself take ~190 bits, so the square can
+//! need ~380 bits.
+
+use cosmwasm_std::{Decimal, Uint128, Uint512};
+
+/// Which direction to round a [`Curve`] computation that can't be
+/// represented exactly in integer atomics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rounding {
+    Down,
+    Up,
+}
+
+/// A bonding curve relating vault token supply to the reserve of base tokens
+/// backing it.
+///
+/// Implementations must keep the three methods consistent with one another:
+/// `reserve` is the definite integral of `spot_price` from `0` to `supply`,
+/// and `supply` is its inverse.
+pub trait Curve {
+    /// The instantaneous price of the next token at the given `supply`.
+    fn spot_price(&self, supply: Uint128) -> Decimal;
+
+    /// The total reserve backing `supply` tokens, i.e. the definite integral
+    /// of `spot_price` from `0` to `supply`.
+    fn reserve(&self, supply: Uint128, rounding: Rounding) -> Uint128;
+
+    /// The supply of tokens backed by `reserve`, i.e. the inverse of
+    /// `reserve`.
+    fn supply(&self, reserve: Uint128, rounding: Rounding) -> Uint128;
+}
+
+/// A constant-price curve: `spot_price = price`, `reserve = price * supply`.
+pub struct Constant {
+    pub price: Decimal,
+}
+
+impl Curve for Constant {
+    fn spot_price(&self, _supply: Uint128) -> Decimal {
+        self.price
+    }
+
+    fn reserve(&self, supply: Uint128, rounding: Rounding) -> Uint128 {
+        match rounding {
+            Rounding::Down => supply.mul_floor(self.price),
+            Rounding::Up => supply.mul_ceil(self.price),
+        }
+    }
+
+    fn supply(&self, reserve: Uint128, rounding: Rounding) -> Uint128 {
+        match rounding {
+            Rounding::Down => reserve.div_floor(self.price),
+            Rounding::Up => reserve.div_ceil(self.price),
+        }
+    }
+}
+
+/// A linear curve: `spot_price = slope * supply`, `reserve = slope *
+/// supply^2 / 2`, `supply = sqrt(2 * reserve / slope)`.
+pub struct Linear {
+    pub slope: Decimal,
+}
+
+impl Curve for Linear {
+    fn spot_price(&self, supply: Uint128) -> Decimal {
+        Decimal::from_ratio(supply, 1u128) * self.slope
+    }
+
+    fn reserve(&self, supply: Uint128, rounding: Rounding) -> Uint128 {
+        // reserve = slope_atomics * supply^2 / (2 * one_atomics), computed as
+        // a single division in Uint512 so the intermediate squaring-then-
+        // scaling-by-slope can't overflow and we only round once.
+        let supply = Uint512::from(supply);
+        let numerator = supply
+            .checked_mul(supply)
+            .unwrap()
+            .checked_mul(Uint512::from(self.slope.atomics()))
+            .unwrap();
+        let denominator = Uint512::from(2u8).checked_mul(one_atomics()).unwrap();
+
+        to_uint128(div_u512(numerator, denominator, rounding))
+    }
+
+    fn supply(&self, reserve: Uint128, rounding: Rounding) -> Uint128 {
+        // supply = sqrt(2 * reserve * one_atomics / slope_atomics).
+        let numerator = Uint512::from(2u8)
+            .checked_mul(Uint512::from(reserve))
+            .unwrap()
+            .checked_mul(one_atomics())
+            .unwrap();
+        let denominator = Uint512::from(self.slope.atomics());
+
+        let radicand = div_u512(numerator, denominator, rounding);
+        to_uint128(isqrt_u512(radicand, rounding))
+    }
+}
+
+/// A square-root curve: `spot_price = slope * sqrt(supply)`, `reserve =
+/// (2/3) * slope * supply^(3/2)`, `supply = (3 * reserve / (2 *
+/// slope))^(2/3)`.
+pub struct SquareRoot {
+    pub slope: Decimal,
+}
+
+impl Curve for SquareRoot {
+    fn spot_price(&self, supply: Uint128) -> Decimal {
+        Decimal::from_ratio(supply.isqrt(), 1u128) * self.slope
+    }
+
+    fn reserve(&self, supply: Uint128, rounding: Rounding) -> Uint128 {
+        // reserve = 2 * slope_atomics * supply^(3/2) / (3 * one_atomics).
+        let supply = Uint512::from(supply);
+        let supply_sqrt = isqrt_u512(supply, rounding);
+        let supply_to_3_over_2 = supply.checked_mul(supply_sqrt).unwrap();
+
+        let numerator = Uint512::from(2u8)
+            .checked_mul(supply_to_3_over_2)
+            .unwrap()
+            .checked_mul(Uint512::from(self.slope.atomics()))
+            .unwrap();
+        let denominator = Uint512::from(3u8).checked_mul(one_atomics()).unwrap();
+
+        to_uint128(div_u512(numerator, denominator, rounding))
+    }
+
+    fn supply(&self, reserve: Uint128, rounding: Rounding) -> Uint128 {
+        // supply = (3 * reserve * one_atomics / (2 * slope_atomics))^(2/3)
+        //        = cbrt((3 * reserve * one_atomics / (2 * slope_atomics))^2).
+        let numerator = Uint512::from(3u8)
+            .checked_mul(Uint512::from(reserve))
+            .unwrap()
+            .checked_mul(one_atomics())
+            .unwrap();
+        let denominator = Uint512::from(2u8)
+            .checked_mul(Uint512::from(self.slope.atomics()))
+            .unwrap();
+
+        let x = div_u512(numerator, denominator, rounding);
+        let x_squared = x.checked_mul(x).unwrap();
+
+        to_uint128(icbrt_u512(x_squared, rounding))
+    }
+}
+
+/// `Decimal::one()` expressed as raw atomics, widened to `Uint512`. Used to
+/// undo the 18-decimal fixed-point scaling of `Decimal::atomics()` values.
+fn one_atomics() -> Uint512 {
+    Uint512::from(Decimal::one().atomics())
+}
+
+/// Narrow a `Uint512` result back down to `Uint128`, which is always
+/// expected to succeed for any realistic vault supply/reserve.
+fn to_uint128(x: Uint512) -> Uint128 {
+    Uint128::try_from(x).expect("curve result overflowed Uint128")
+}
+
+/// Divide `numerator` by `denominator`, rounding in the given direction.
+fn div_u512(numerator: Uint512, denominator: Uint512, rounding: Rounding) -> Uint512 {
+    let quotient = numerator / denominator;
+
+    match rounding {
+        Rounding::Down => quotient,
+        Rounding::Up => {
+            if quotient * denominator == numerator {
+                quotient
+            } else {
+                quotient + Uint512::one()
+            }
+        }
+    }
+}
+
+/// The number of bits needed to represent `x`, i.e. `0` for `x == 0` and
+/// `floor(log2(x)) + 1` otherwise.
+fn bit_length(mut x: Uint512) -> u32 {
+    let mut bits = 0u32;
+    while !x.is_zero() {
+        x >>= 1u32;
+        bits += 1;
+    }
+
+    bits
+}
+
+/// Integer square root of `x`, rounded in the given direction, via Newton's
+/// method. The starting guess is derived from `x`'s bit length so the first
+/// iteration can't overflow `Uint512`.
+fn isqrt_u512(x: Uint512, rounding: Rounding) -> Uint512 {
+    if x.is_zero() {
+        return Uint512::zero();
+    }
+
+    let mut guess = Uint512::one() << ((bit_length(x) / 2) + 1);
+    loop {
+        let next = (guess + x / guess) >> 1u32;
+        if next >= guess {
+            break;
+        }
+        guess = next;
+    }
+
+    while (guess + Uint512::one()) * (guess + Uint512::one()) <= x {
+        guess += Uint512::one();
+    }
+    while guess * guess > x {
+        guess -= Uint512::one();
+    }
+
+    match rounding {
+        Rounding::Down => guess,
+        Rounding::Up => {
+            if guess * guess == x {
+                guess
+            } else {
+                guess + Uint512::one()
+            }
+        }
+    }
+}
+
+/// Integer cube root of `x`, rounded in the given direction, via Newton's
+/// method. The starting guess is derived from `x`'s bit length so the first
+/// iteration can't overflow `Uint512`.
+fn icbrt_u512(x: Uint512, rounding: Rounding) -> Uint512 {
+    if x.is_zero() {
+        return Uint512::zero();
+    }
+
+    let mut guess = Uint512::one() << ((bit_length(x) / 3) + 1);
+    loop {
+        let guess_sq = guess * guess;
+        let next = (Uint512::from(2u8) * guess + x / guess_sq) / Uint512::from(3u8);
+        if next >= guess {
+            break;
+        }
+        guess = next;
+    }
+
+    while (guess + Uint512::one()).pow(3) <= x {
+        guess += Uint512::one();
+    }
+    while guess.pow(3) > x {
+        guess -= Uint512::one();
+    }
+
+    match rounding {
+        Rounding::Down => guess,
+        Rounding::Up => {
+            if guess.pow(3) == x {
+                guess
+            } else {
+                guess + Uint512::one()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_curve_round_trips() {
+        let curve = Constant {
+            price: Decimal::percent(150),
+        };
+
+        assert_eq!(
+            curve.reserve(Uint128::new(100), Rounding::Down),
+            Uint128::new(150)
+        );
+        assert_eq!(
+            curve.supply(Uint128::new(150), Rounding::Down),
+            Uint128::new(100)
+        );
+    }
+
+    #[test]
+    fn linear_curve_matches_closed_form() {
+        let curve = Linear {
+            slope: Decimal::one(),
+        };
+
+        // reserve = slope * supply^2 / 2 = 1 * 10^2 / 2 = 50.
+        assert_eq!(
+            curve.reserve(Uint128::new(10), Rounding::Down),
+            Uint128::new(50)
+        );
+        // supply = sqrt(2 * reserve / slope) = sqrt(100) = 10.
+        assert_eq!(
+            curve.supply(Uint128::new(50), Rounding::Down),
+            Uint128::new(10)
+        );
+    }
+
+    #[test]
+    fn linear_curve_does_not_overflow_for_realistic_large_supply() {
+        let curve = Linear {
+            slope: Decimal::one(),
+        };
+
+        // supply = 10^30 with slope = 1 panicked under the old Uint256
+        // widening, since supply^2 (10^60) * slope_atomics (10^18) = 10^78
+        // exceeds Uint256::MAX (~1.16 * 10^77). This must not panic.
+        let supply = Uint128::new(1_000_000_000_000_000_000_000_000_000_000u128);
+        let reserve = curve.reserve(supply, Rounding::Down);
+        assert!(!reserve.is_zero());
+
+        // Round-tripping back through `supply` should land close to the
+        // original value.
+        let recovered = curve.supply(reserve, Rounding::Down);
+        assert!(recovered <= supply);
+    }
+
+    #[test]
+    fn square_root_curve_round_trip_does_not_exceed_supply() {
+        let curve = SquareRoot {
+            slope: Decimal::one(),
+        };
+
+        // reserve = (2/3) * slope * supply^(3/2) = (2/3) * 8^1.5 = (2/3)*22.6..
+        // supply = 8 is not a perfect square, so compare via round-trip
+        // instead of a hand-computed closed form.
+        let reserve = curve.reserve(Uint128::new(8), Rounding::Down);
+        let supply = curve.supply(reserve, Rounding::Down);
+        assert!(supply <= Uint128::new(8));
+    }
+
+    #[test]
+    fn square_root_curve_does_not_overflow_for_realistic_large_supply() {
+        let curve = SquareRoot {
+            slope: Decimal::percent(250),
+        };
+
+        let supply = Uint128::new(1_000_000_000_000_000_000_000_000_000_000u128);
+        let reserve = curve.reserve(supply, Rounding::Down);
+        assert!(!reserve.is_zero());
+    }
+
+    #[test]
+    fn rounding_up_is_never_smaller_than_rounding_down() {
+        let curve = SquareRoot {
+            slope: Decimal::percent(250),
+        };
+
+        for supply in [1u128, 2, 3, 7, 1_000, 123_456_789] {
+            let supply = Uint128::new(supply);
+            let down = curve.reserve(supply, Rounding::Down);
+            let up = curve.reserve(supply, Rounding::Up);
+            assert!(up >= down);
+        }
+    }
+
+    #[test]
+    fn icbrt_matches_perfect_and_non_perfect_cubes() {
+        assert_eq!(
+            icbrt_u512(Uint512::from(27u128), Rounding::Down),
+            Uint512::from(3u128)
+        );
+        assert_eq!(
+            icbrt_u512(Uint512::from(27u128), Rounding::Up),
+            Uint512::from(3u128)
+        );
+        assert_eq!(
+            icbrt_u512(Uint512::from(26u128), Rounding::Down),
+            Uint512::from(2u128)
+        );
+        assert_eq!(
+            icbrt_u512(Uint512::from(26u128), Rounding::Up),
+            Uint512::from(3u128)
+        );
+    }
+
+    #[test]
+    fn icbrt_does_not_overflow_for_huge_inputs() {
+        // A value whose cube root is itself far beyond u128::MAX, exercising
+        // the bit-length-seeded Newton's method in full Uint512 arithmetic.
+        let x = Uint512::from(10u128).pow(150);
+        let root = icbrt_u512(x, Rounding::Down);
+        assert!(root.pow(3) <= x);
+        assert!((root + Uint512::one()).pow(3) > x);
+    }
+}
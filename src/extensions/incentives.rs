@@ -0,0 +1,31 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Coin;
+
+/// Extension execute messages for the incentives extension.
+///
+/// Standardizes how vaults that auto-compound or pass through external
+/// incentives (e.g. a Quasar-style concentrated-liquidity vault, or a vault
+/// that farms a money-market's reward token) expose them, so that wallets and
+/// integrators have one interface for collecting and claiming rewards across
+/// different vault implementations.
+#[cw_serde]
+pub enum IncentivesExecuteMsg {
+    /// Permissionlessly claim any pending external incentives into the vault,
+    /// so that they become part of `TotalAssets` or are queued up for
+    /// `DistributeRewards`, depending on the vault's implementation.
+    CollectRewards {},
+
+    /// Distribute collected rewards to vault token holders.
+    DistributeRewards {},
+}
+
+/// Extension query messages for the incentives extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum IncentivesQueryMsg {
+    /// Returns the rewards that are pending distribution. If `user` is set,
+    /// returns the rewards owed to that specific user; otherwise returns the
+    /// rewards pending for the vault as a whole.
+    #[returns(Vec<Coin>)]
+    PendingRewards { user: Option<String> },
+}
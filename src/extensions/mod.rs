@@ -0,0 +1,10 @@
+#[cfg(feature = "force-unlock")]
+pub mod force_unlock;
+#[cfg(feature = "rewards")]
+pub mod incentives;
+#[cfg(feature = "keeper")]
+pub mod keeper;
+#[cfg(feature = "lockup")]
+pub mod lockup;
+#[cfg(feature = "performance-fee")]
+pub mod performance_fee;
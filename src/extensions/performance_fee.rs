@@ -0,0 +1,55 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::{Decimal, Uint128};
+
+/// Configuration for the performance fee extension.
+///
+/// The vault charges a fee on gains in the share price (i.e.
+/// `total_base_tokens / total_vault_tokens`) above the previous all-time-high
+/// price, known as the high-water mark. This ensures fees are only ever
+/// charged on gains, never on principal, and never twice on the same gain.
+#[cw_serde]
+pub struct PerformanceFeeConfig {
+    /// The fraction of gains above the high-water mark taken as a fee, e.g.
+    /// `Decimal::percent(20)` for a 20% performance fee.
+    pub fee_rate: Decimal,
+    /// The minimum number of seconds that must pass between two consecutive
+    /// calls to `WithdrawPerformanceFee`.
+    pub withdrawal_interval_s: u64,
+}
+
+/// Extension execute messages for the performance fee extension.
+///
+/// Implementing vaults MUST track a high-water-mark share price, updated
+/// whenever fees are realized, so that `VaultStandardQueryMsg::PreviewRedeem`
+/// and `VaultStandardQueryMsg::ConvertToAssets` always return values net of
+/// the accrued-but-unclaimed performance fee. Integrators pricing vault
+/// positions off of those queries will therefore never overstate a position
+/// by the fee the vault is about to take.
+#[cw_serde]
+pub enum PerformanceFeeExecuteMsg {
+    /// Update the performance fee configuration. Only callable by the vault
+    /// admin.
+    UpdateConfig { config: PerformanceFeeConfig },
+
+    /// Realize the accrued performance fee, transferring it to the fee
+    /// recipient and advancing the high-water mark to the current share
+    /// price. Fails if called before `withdrawal_interval_s` seconds have
+    /// elapsed since the last withdrawal.
+    WithdrawPerformanceFee {},
+}
+
+/// Extension query messages for the performance fee extension.
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum PerformanceFeeQueryMsg {
+    /// Returns the current `PerformanceFeeConfig`.
+    #[returns(PerformanceFeeConfig)]
+    PerformanceFeeConfig {},
+
+    /// Returns the amount of base tokens that would be withdrawn as
+    /// performance fee if `PerformanceFeeExecuteMsg::WithdrawPerformanceFee`
+    /// were called now, i.e. the fee on all gains in share price above the
+    /// current high-water mark.
+    #[returns(Uint128)]
+    AccruedPerformanceFee {},
+}
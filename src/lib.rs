@@ -0,0 +1,6 @@
+pub mod asset;
+#[cfg(feature = "curve")]
+pub mod curve;
+pub mod extensions;
+pub mod msg;
+pub mod querier;
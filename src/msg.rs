@@ -1,14 +1,20 @@
 #[cfg(feature = "force-unlock")]
 use crate::extensions::force_unlock::ForceUnlockExecuteMsg;
+#[cfg(feature = "rewards")]
+use crate::extensions::incentives::{IncentivesExecuteMsg, IncentivesQueryMsg};
 #[cfg(feature = "keeper")]
 use crate::extensions::keeper::{KeeperExecuteMsg, KeeperQueryMsg};
 #[cfg(feature = "lockup")]
 use crate::extensions::lockup::{LockupExecuteMsg, LockupQueryMsg};
+#[cfg(feature = "performance-fee")]
+use crate::extensions::performance_fee::{PerformanceFeeExecuteMsg, PerformanceFeeQueryMsg};
 
 use cosmwasm_schema::{cw_serde, QueryResponses};
-use cosmwasm_std::{Empty, Uint128};
+use cosmwasm_std::{Decimal, Empty, Uint128};
 use schemars::JsonSchema;
 
+use crate::asset::AssetInfo;
+
 /// The default ExecuteMsg variants that all vaults must implement.
 /// This enum can be extended with additional variants by defining an extension
 /// enum and then passing it as the generic argument `T` to this enum.
@@ -57,6 +63,10 @@ pub enum ExtensionExecuteMsg {
     Lockup(LockupExecuteMsg),
     #[cfg(feature = "force-unlock")]
     ForceUnlock(ForceUnlockExecuteMsg),
+    #[cfg(feature = "performance-fee")]
+    PerformanceFee(PerformanceFeeExecuteMsg),
+    #[cfg(feature = "rewards")]
+    Incentives(IncentivesExecuteMsg),
 }
 
 /// The default QueryMsg variants that all vaults must implement.
@@ -101,6 +111,11 @@ where
     /// Returns the number of base tokens that would be redeemed in exchange
     /// `amount` for vault tokens. Used by Rover to calculate vault position
     /// values.
+    ///
+    /// If the `performance-fee` extension is enabled, this MUST be net of
+    /// the accrued-but-unclaimed performance fee, so that integrators pricing
+    /// a position off of this query never overstate it by the fee the vault
+    /// is about to take.
     #[returns(Uint128)]
     PreviewRedeem { amount: Uint128 },
 
@@ -122,6 +137,11 @@ where
     /// This calculation may not reflect the “per-user” price-per-share, and
     /// instead should reflect the “average-user’s” price-per-share, meaning
     /// what the average user should expect to see when exchanging to and from.
+    ///
+    /// If the `curve` feature is used, this is computed from the vault's
+    /// [`crate::curve::Curve`] as `supply(reserve + amount, Rounding::Down) -
+    /// supply(reserve, Rounding::Up)` instead of the default linear `assets /
+    /// supply` ratio, so the curve never mints more shares than it backs.
     #[returns(Uint128)]
     ConvertToShares { amount: Uint128 },
 
@@ -134,9 +154,29 @@ where
     /// This calculation may not reflect the “per-user” price-per-share, and
     /// instead should reflect the “average-user’s” price-per-share, meaning
     /// what the average user should expect to see when exchanging to and from.
+    ///
+    /// If the `performance-fee` extension is enabled, this MUST be net of
+    /// the accrued-but-unclaimed performance fee.
+    ///
+    /// If the `curve` feature is used, this is computed from the vault's
+    /// [`crate::curve::Curve`] as `reserve(supply, Rounding::Down) -
+    /// reserve(supply - amount, Rounding::Up)` instead of the default linear
+    /// `assets / supply` ratio, so the curve never pays out more reserve than
+    /// it holds.
     #[returns(Uint128)]
     ConvertToAssets { amount: Uint128 },
 
+    /// Returns the current price of one vault token denominated in base
+    /// tokens, i.e. `total_base_tokens / total_vault_tokens`. Defined as
+    /// `1.0` when `total_vault_tokens` is zero.
+    ///
+    /// Lets integrators read assets, supply, and derived price in one
+    /// round-trip instead of composing `TotalAssets` and
+    /// `TotalVaultTokenSupply` themselves, which avoids rounding drift from
+    /// client-side division.
+    #[returns(Decimal)]
+    SharePrice {},
+
     /// Handle quries of any enabled extensions.
     #[returns(Empty)]
     VaultExtension(T),
@@ -151,6 +191,10 @@ pub enum ExtensionQueryMsg {
     Keeper(KeeperQueryMsg),
     #[cfg(feature = "lockup")]
     Lockup(LockupQueryMsg),
+    #[cfg(feature = "performance-fee")]
+    PerformanceFee(PerformanceFeeQueryMsg),
+    #[cfg(feature = "rewards")]
+    Incentives(IncentivesQueryMsg),
 }
 
 /// Struct returned from QueryMsg::VaultStandardInfo with information about the
@@ -171,11 +215,18 @@ pub struct VaultStandardInfoResponse {
 /// Returned by QueryMsg::Info and contains information about this vault
 #[cw_serde]
 pub struct VaultInfoResponse {
-    /// The token that is accepted for deposits, withdrawals and used for
-    /// accounting in the vault. The denom if it is a native token and the
-    /// contract address if it is a cw20 token.
-    pub base_token: String,
-    /// Vault token. The denom if it is a native token and the contract address
-    /// if it is a cw20 token.
-    pub vault_token: String,
+    /// The asset that is accepted for deposits, withdrawals and used for
+    /// accounting in the vault, carrying whether it is a native token or a
+    /// cw20 contract explicitly rather than relying on string-shape
+    /// conventions.
+    pub base_token: AssetInfo,
+    /// The vault token, carrying whether it is a native token or a cw20
+    /// contract explicitly rather than relying on string-shape conventions.
+    pub vault_token: AssetInfo,
+    /// The total amount of assets managed by the vault, denominated in base
+    /// tokens. Equal to the result of `QueryMsg::TotalAssets`.
+    pub total_base_tokens: Uint128,
+    /// The total amount of vault tokens in circulation. Equal to the result
+    /// of `QueryMsg::TotalVaultTokenSupply`.
+    pub total_vault_tokens: Uint128,
 }
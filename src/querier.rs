@@ -0,0 +1,86 @@
+//! A shared querier for balances, transfers, and total supply of an
+//! [`AssetInfo`], so code that must support both native and cw20 base/vault
+//! tokens doesn't need to duplicate the dispatch at every call site.
+
+use cosmwasm_std::{
+    to_json_binary, Addr, BankMsg, Coin, CosmosMsg, Deps, StdResult, Uint128, WasmMsg,
+};
+use cw20::{BalanceResponse, Cw20ExecuteMsg, Cw20QueryMsg, TokenInfoResponse};
+
+use crate::asset::AssetInfo;
+
+/// A hook for querying the balance of a native asset.
+///
+/// Most chains can use the default [`BankQuerier`], but chains with their
+/// own native-supply semantics (e.g. Coreum smart tokens) can implement this
+/// trait to plug in a custom query instead of the bank module.
+pub trait NativeBalanceQuerier {
+    fn query_native_balance(&self, deps: Deps, denom: &str, address: &Addr) -> StdResult<Uint128>;
+}
+
+/// The default [`NativeBalanceQuerier`], backed by the bank module.
+pub struct BankQuerier;
+
+impl NativeBalanceQuerier for BankQuerier {
+    fn query_native_balance(&self, deps: Deps, denom: &str, address: &Addr) -> StdResult<Uint128> {
+        Ok(deps.querier.query_balance(address, denom)?.amount)
+    }
+}
+
+/// Query the balance of `asset` held by `address`, dispatching on whether it
+/// is a native or cw20 asset. Native balances are queried via `native_querier`:
+/// pass [`BankQuerier`] to use the bank module, or a custom implementation on
+/// chains with custom native-token semantics.
+pub fn query_balance(
+    deps: Deps,
+    asset: &AssetInfo,
+    address: &Addr,
+    native_querier: &dyn NativeBalanceQuerier,
+) -> StdResult<Uint128> {
+    match asset {
+        AssetInfo::Native(denom) => native_querier.query_native_balance(deps, denom, address),
+        AssetInfo::Cw20(contract_addr) => {
+            let res: BalanceResponse = deps.querier.query_wasm_smart(
+                contract_addr,
+                &Cw20QueryMsg::Balance {
+                    address: address.to_string(),
+                },
+            )?;
+            Ok(res.balance)
+        }
+    }
+}
+
+/// Returns the total supply of `asset`.
+pub fn total_supply(deps: Deps, asset: &AssetInfo) -> StdResult<Uint128> {
+    match asset {
+        AssetInfo::Native(denom) => Ok(deps.querier.query_supply(denom)?.amount),
+        AssetInfo::Cw20(contract_addr) => {
+            let res: TokenInfoResponse = deps
+                .querier
+                .query_wasm_smart(contract_addr, &Cw20QueryMsg::TokenInfo {})?;
+            Ok(res.total_supply)
+        }
+    }
+}
+
+/// Builds the `CosmosMsg` that transfers `amount` of `asset` to `recipient`.
+pub fn transfer_msg(asset: &AssetInfo, recipient: &Addr, amount: Uint128) -> StdResult<CosmosMsg> {
+    Ok(match asset {
+        AssetInfo::Native(denom) => CosmosMsg::Bank(BankMsg::Send {
+            to_address: recipient.to_string(),
+            amount: vec![Coin {
+                denom: denom.clone(),
+                amount,
+            }],
+        }),
+        AssetInfo::Cw20(contract_addr) => CosmosMsg::Wasm(WasmMsg::Execute {
+            contract_addr: contract_addr.to_string(),
+            msg: to_json_binary(&Cw20ExecuteMsg::Transfer {
+                recipient: recipient.to_string(),
+                amount,
+            })?,
+            funds: vec![],
+        }),
+    })
+}